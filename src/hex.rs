@@ -1,5 +1,7 @@
 use godot::prelude::*;
+use godot::classes::MeshInstance3D;
 use glam::Vec3;
+use crate::mesh::build_tile_mesh;
 
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
 pub struct HexCoord {
@@ -16,6 +18,8 @@ pub struct HexTile {
     coord: HexCoord,
     world_pos: Vec3,
     neighbors: Vec<Gd<HexTile>>,
+    polygon: Vec<Vec3>,
+    is_pentagon: bool,
 }
 
 #[godot_api]
@@ -35,9 +39,28 @@ impl HexTile {
         self.coord = HexCoord::new(q, r);
     }
 
+    #[func]
+    pub fn get_polygon_vertices(&self) -> PackedVector3Array {
+        self.polygon.iter()
+            .map(|v| Vector3::new(v.x, v.y, v.z))
+            .collect()
+    }
+
+    #[func]
+    pub fn is_pentagon(&self) -> bool {
+        self.is_pentagon
+    }
+
     pub fn connect_neighbors(&mut self, neighbors: Vec<Gd<HexTile>>) {
         self.neighbors = neighbors;
     }
+
+    /// Sets the dual-polygon ring (in the tile's local space) used to build
+    /// its mesh geometry, and whether it's one of the 12 pentagon tiles.
+    pub fn set_polygon(&mut self, polygon: Vec<Vec3>, is_pentagon: bool) {
+        self.polygon = polygon;
+        self.is_pentagon = is_pentagon;
+    }
 }
 
 #[godot_api]
@@ -48,21 +71,22 @@ impl INode3D for HexTile {
             coord: HexCoord::new(0, 0),
             world_pos: Vec3::ZERO,
             neighbors: Vec::new(),
+            polygon: Vec::new(),
+            is_pentagon: false,
         }
     }
 
     fn ready(&mut self) {
-        // Create basic visual representation
-        let mut child = Node3D::new_alloc();
-        let transform = Transform3D::new(
-            Basis::from_diagonal(0.5, 0.2, 0.5),
-            Vector3::ZERO
-        );
-        child.set_transform(transform);
-        
+        if self.polygon.is_empty() {
+            return;
+        }
+
+        let mut mesh_instance = MeshInstance3D::new_alloc();
+        mesh_instance.set_mesh(&build_tile_mesh(&self.polygon));
+
         unsafe {
             let parent_node = self.base.to_gd();
-            child.reparent(&parent_node.upcast::<Node>());
+            mesh_instance.reparent(&parent_node.upcast::<Node>());
         }
     }
 }
@@ -1,142 +1,193 @@
-use glam::{Vec2, Vec3, Mat3};
+use glam::Vec3;
+use std::collections::{HashMap, HashSet};
 use crate::hex::HexCoord;
-use crate::math::projection::IcosahedronFace;
-use std::collections::HashMap;
-
-pub const SQRT_3: f32 = 1.7320508075688772;
+use crate::math::projection::{build_from_conway_notation, generate_icosphere, IcoMesh, PolyMesh};
 
 pub struct HexGridSettings {
-    pub hex_size: f32,
-    pub grid_radius: i32,
+    pub resolution: i32,
+    /// A Conway-notation string (e.g. `"tI"` for a soccer-ball truncated
+    /// icosahedron). When set to a recognized notation, this selects the
+    /// grid's topology directly and `resolution` is ignored; when `None` or
+    /// unrecognized, the grid falls back to the subdivided-icosphere dual.
+    pub topology: Option<String>,
 }
 
-pub struct HexGrid {
-    settings: HexGridSettings,
-    hex_positions: HashMap<HexCoord, Vec3>,
-    face_grids: Vec<FaceGrid>,
+/// One tile of the dual polyhedron: the centroids of every mesh triangle
+/// incident to a source vertex, wound angularly into a polygon around it.
+/// For a Conway topology the tile is instead one face of the polyhedron,
+/// with that face's own vertices as its polygon (see `tiles_from_polymesh`).
+pub struct GridTile {
+    pub coord: HexCoord,
+    pub vertex_index: usize,
+    pub position: Vec3,
+    pub polygon: Vec<Vec3>,
+    pub neighbor_coords: Vec<HexCoord>,
+    pub is_pentagon: bool,
 }
 
-struct FaceGrid {
-    face: IcosahedronFace,
-    local_coords: Vec<HexCoord>,
-    center: Vec3,
-    orientation: Mat3,
+pub struct HexGrid {
+    settings: HexGridSettings,
+    mesh: Option<IcoMesh>,
+    tiles: Vec<GridTile>,
 }
 
 impl HexGrid {
     pub fn new(settings: HexGridSettings) -> Self {
-        Self {
-            settings,
-            hex_positions: HashMap::new(),
-            face_grids: Vec::new(),
-        }
+        let (mesh, tiles) = match settings.topology.as_deref().and_then(build_from_conway_notation) {
+            Some(poly_mesh) => (None, tiles_from_polymesh(&poly_mesh)),
+            None => {
+                let mesh = generate_icosphere(settings.resolution);
+                let tiles = build_dual_tiles(&mesh);
+                (Some(mesh), tiles)
+            }
+        };
+
+        Self { settings, mesh, tiles }
     }
 
-    pub fn generate_on_face(&mut self, face: IcosahedronFace) {
-        let center = face.center();
-        
-        // Calculate face orientation
-        let normal = face.normal;
-        let tangent = (face.vertices[1] - face.vertices[0]).normalize();
-        let bitangent = normal.cross(tangent);
-        let orientation = Mat3::from_cols(tangent, bitangent, normal);
-
-        let mut local_coords = Vec::new();
-        let radius = self.settings.grid_radius as f32;
-
-        // Generate hex grid in 2D face space
-        for q in -self.settings.grid_radius..=self.settings.grid_radius {
-            for r in -self.settings.grid_radius..=self.settings.grid_radius {
-                let s = -q - r;
-                if s.abs() <= self.settings.grid_radius {
-                    let coord = HexCoord::new(q, r);
-                    let pos_2d = hex_to_pixel(coord, self.settings.hex_size);
-                    
-                    // Project onto face plane
-                    let pos_3d = orientation.mul_vec3(Vec3::new(pos_2d.x, pos_2d.y, 0.0));
-                    let world_pos = project_onto_sphere(center + pos_3d);
-                    
-                    self.hex_positions.insert(coord, world_pos);
-                    local_coords.push(coord);
-                }
-            }
-        }
+    pub fn resolution(&self) -> i32 {
+        self.settings.resolution
+    }
+
+    pub fn mesh(&self) -> Option<&IcoMesh> {
+        self.mesh.as_ref()
+    }
 
-        self.face_grids.push(FaceGrid {
-            face,
-            local_coords,
-            center,
-            orientation,
-        });
+    pub fn tiles(&self) -> &[GridTile] {
+        &self.tiles
     }
 
     pub fn get_neighbor_positions(&self, coord: &HexCoord) -> Vec<Vec3> {
-        coord.neighbors().iter()
-            .filter_map(|n| self.hex_positions.get(n))
-            .copied()
+        let Some(tile) = self.tiles.iter().find(|t| t.coord == *coord) else {
+            return Vec::new();
+        };
+
+        tile.neighbor_coords.iter()
+            .filter_map(|n| self.tiles.iter().find(|t| t.coord == *n))
+            .map(|t| t.position)
             .collect()
     }
+}
 
-    pub fn get_all_positions(&self) -> &HashMap<HexCoord, Vec3> {
-        &self.hex_positions
-    }
+/// Vertices that descend from the original 12 icosahedron corners yield
+/// pentagons; `generate_icosphere` always emits those corners first.
+fn is_pentagon_vertex(vertex_index: usize) -> bool {
+    vertex_index < 12
+}
 
-    pub fn find_hex_at_point(&self, point: Vec3) -> Option<HexCoord> {
-        // Find closest face first
-        let closest_face = self.face_grids.iter()
-            .min_by(|a, b| {
-                let dist_a = a.center.distance(point);
-                let dist_b = b.center.distance(point);
-                dist_a.partial_cmp(&dist_b).unwrap()
-            })?;
-
-        // Transform point to face local space
-        let local_point = closest_face.orientation.transpose() * (point - closest_face.center);
-        let hex_coord = pixel_to_hex(Vec2::new(local_point.x, local_point.y), self.settings.hex_size);
-
-        // Verify the hex exists
-        if self.hex_positions.contains_key(&hex_coord) {
-            Some(hex_coord)
-        } else {
-            None
+fn build_dual_tiles(mesh: &IcoMesh) -> Vec<GridTile> {
+    let mut incident_faces: Vec<Vec<usize>> = vec![Vec::new(); mesh.vertices.len()];
+    for (face_index, face) in mesh.faces.iter().enumerate() {
+        for &vertex in face {
+            incident_faces[vertex].push(face_index);
         }
     }
-}
 
-fn hex_to_pixel(hex: HexCoord, size: f32) -> Vec2 {
-    Vec2::new(
-        size * (3.0/2.0 * hex.q as f32),
-        size * (SQRT_3/2.0 * hex.q as f32 + SQRT_3 * hex.r as f32)
-    )
+    let face_centroids: Vec<Vec3> = mesh.faces.iter()
+        .map(|[a, b, c]| ((mesh.vertices[*a] + mesh.vertices[*b] + mesh.vertices[*c]) / 3.0).normalize())
+        .collect();
+
+    mesh.vertices.iter().enumerate()
+        .map(|(vertex_index, &position)| {
+            let normal = position.normalize();
+            let mut polygon: Vec<Vec3> = incident_faces[vertex_index].iter()
+                .map(|&f| face_centroids[f])
+                .collect();
+            sort_polygon_around_normal(&mut polygon, position, normal);
+
+            let mut neighbor_set = HashSet::new();
+            for &face_index in &incident_faces[vertex_index] {
+                for &other in &mesh.faces[face_index] {
+                    if other != vertex_index {
+                        neighbor_set.insert(other);
+                    }
+                }
+            }
+            let mut neighbor_indices: Vec<usize> = neighbor_set.into_iter().collect();
+            neighbor_indices.sort_unstable();
+            let neighbor_coords = neighbor_indices.into_iter()
+                .map(|n| HexCoord::new(n as i32, 0))
+                .collect();
+
+            GridTile {
+                coord: HexCoord::new(vertex_index as i32, 0),
+                vertex_index,
+                position,
+                polygon,
+                neighbor_coords,
+                is_pentagon: is_pentagon_vertex(vertex_index),
+            }
+        })
+        .collect()
 }
 
-fn pixel_to_hex(point: Vec2, size: f32) -> HexCoord {
-    let q = (2.0/3.0 * point.x) / size;
-    let r = (-1.0/3.0 * point.x + SQRT_3/3.0 * point.y) / size;
-    let s = -q - r;
-
-    // Round to nearest hex
-    let mut rq = q.round();
-    let mut rr = r.round();
-    let mut rs = s.round();
-
-    let q_diff = (rq - q).abs();
-    let r_diff = (rr - r).abs();
-    let s_diff = (rs - s).abs();
-
-    if q_diff > r_diff && q_diff > s_diff {
-        rq = -rr - rs;
-    } else if r_diff > s_diff {
-        rr = -rq - rs;
-    } else {
-        rs = -rq - rr;
+/// Builds one `GridTile` per face of a Conway/Hart `PolyMesh`, the direct
+/// analogue of `build_dual_tiles` for meshes that are already the tiling
+/// (rather than a shared-vertex triangle mesh whose dual is the tiling).
+fn tiles_from_polymesh(mesh: &PolyMesh) -> Vec<GridTile> {
+    let mut edge_faces: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+    for (face_index, face) in mesh.faces.iter().enumerate() {
+        let n = face.len();
+        for i in 0..n {
+            let a = face[i];
+            let b = face[(i + 1) % n];
+            let key = if a < b { (a, b) } else { (b, a) };
+            edge_faces.entry(key).or_default().push(face_index);
+        }
     }
 
-    HexCoord::new(rq as i32, rr as i32)
+    mesh.faces.iter().enumerate()
+        .map(|(face_index, face)| {
+            let polygon: Vec<Vec3> = face.iter().map(|&v| mesh.vertices[v]).collect();
+            let position = (polygon.iter().copied().sum::<Vec3>() / polygon.len() as f32).normalize();
+
+            let n = face.len();
+            let mut neighbor_set = HashSet::new();
+            for i in 0..n {
+                let a = face[i];
+                let b = face[(i + 1) % n];
+                let key = if a < b { (a, b) } else { (b, a) };
+                for &other in &edge_faces[&key] {
+                    if other != face_index {
+                        neighbor_set.insert(other);
+                    }
+                }
+            }
+            let mut neighbor_indices: Vec<usize> = neighbor_set.into_iter().collect();
+            neighbor_indices.sort_unstable();
+            let neighbor_coords = neighbor_indices.into_iter()
+                .map(|n| HexCoord::new(n as i32, 0))
+                .collect();
+
+            GridTile {
+                coord: HexCoord::new(face_index as i32, 0),
+                vertex_index: face_index,
+                position,
+                polygon,
+                neighbor_coords,
+                is_pentagon: face.len() == 5,
+            }
+        })
+        .collect()
 }
 
-fn project_onto_sphere(point: Vec3) -> Vec3 {
-    point.normalize()
+/// Orders the face centroids around `position` so they form a proper polygon
+/// ring instead of an arbitrary incidence order.
+fn sort_polygon_around_normal(polygon: &mut [Vec3], position: Vec3, normal: Vec3) {
+    if polygon.len() < 2 {
+        return;
+    }
+
+    let reference = polygon[0] - position;
+    let tangent = (reference - normal * reference.dot(normal)).normalize();
+    let bitangent = normal.cross(tangent);
+
+    let angle_of = |p: Vec3| {
+        let v = p - position;
+        v.dot(bitangent).atan2(v.dot(tangent))
+    };
+
+    polygon.sort_by(|a, b| angle_of(*a).partial_cmp(&angle_of(*b)).unwrap());
 }
 
 #[cfg(test)]
@@ -144,31 +195,66 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_hex_to_pixel_to_hex() {
-        let coord = HexCoord::new(2, -1);
-        let size = 1.0;
-        let pixel = hex_to_pixel(coord, size);
-        let result = pixel_to_hex(pixel, size);
-        assert_eq!(coord.q, result.q);
-        assert_eq!(coord.r, result.r);
+    fn test_dual_produces_twelve_pentagons() {
+        let grid = HexGrid::new(HexGridSettings { resolution: 1, topology: None });
+        let pentagon_count = grid.tiles().iter().filter(|t| t.is_pentagon).count();
+        assert_eq!(pentagon_count, 12);
     }
 
     #[test]
-    fn test_neighbor_positions() {
-        let settings = HexGridSettings {
-            hex_size: 1.0,
-            grid_radius: 2,
-        };
-        let mut grid = HexGrid::new(settings);
-        let face = IcosahedronFace::new(
-            Vec3::new(0.0, 0.0, 1.0),
-            Vec3::new(1.0, 0.0, 0.0),
-            Vec3::new(0.0, 1.0, 0.0)
-        );
-        grid.generate_on_face(face);
-
-        let center = HexCoord::new(0, 0);
-        let neighbors = grid.get_neighbor_positions(&center);
-        assert_eq!(neighbors.len(), 6);
+    fn test_pentagon_tiles_have_five_neighbors() {
+        let grid = HexGrid::new(HexGridSettings { resolution: 2, topology: None });
+        for tile in grid.tiles().iter().filter(|t| t.is_pentagon) {
+            assert_eq!(tile.neighbor_coords.len(), 5);
+            assert_eq!(tile.polygon.len(), 5);
+        }
+    }
+
+    #[test]
+    fn test_hexagon_tiles_have_six_neighbors() {
+        let grid = HexGrid::new(HexGridSettings { resolution: 2, topology: None });
+        for tile in grid.tiles().iter().filter(|t| !t.is_pentagon) {
+            assert_eq!(tile.neighbor_coords.len(), 6);
+            assert_eq!(tile.polygon.len(), 6);
+        }
+    }
+
+    #[test]
+    fn test_no_duplicate_coords() {
+        let grid = HexGrid::new(HexGridSettings { resolution: 1, topology: None });
+        let mut coords: Vec<_> = grid.tiles().iter().map(|t| t.coord).collect();
+        let before = coords.len();
+        coords.sort_by_key(|c| c.q);
+        coords.dedup();
+        assert_eq!(coords.len(), before);
+    }
+
+    #[test]
+    fn test_neighbor_coords_are_sorted_deterministically() {
+        let grid = HexGrid::new(HexGridSettings { resolution: 1, topology: None });
+        for tile in grid.tiles() {
+            let mut sorted = tile.neighbor_coords.clone();
+            sorted.sort_by_key(|c| c.q);
+            assert_eq!(tile.neighbor_coords, sorted);
+        }
+    }
+
+    #[test]
+    fn test_conway_topology_produces_soccer_ball_tiles() {
+        let grid = HexGrid::new(HexGridSettings { resolution: 1, topology: Some("tI".to_string()) });
+        assert!(grid.mesh().is_none());
+
+        let pentagons = grid.tiles().iter().filter(|t| t.is_pentagon).count();
+        let hexagons = grid.tiles().iter().filter(|t| !t.is_pentagon).count();
+        assert_eq!(pentagons, 12);
+        assert_eq!(hexagons, 20);
+    }
+
+    #[test]
+    fn test_invalid_topology_falls_back_to_resolution_based_dual() {
+        let grid = HexGrid::new(HexGridSettings { resolution: 1, topology: Some("nonsense".to_string()) });
+        assert!(grid.mesh().is_some());
+        let pentagon_count = grid.tiles().iter().filter(|t| t.is_pentagon).count();
+        assert_eq!(pentagon_count, 12);
     }
 }
@@ -4,6 +4,9 @@ pub mod hex;
 pub mod sphere_grid;
 pub mod math;
 pub mod hex_grid;
+pub mod mesh;
+pub mod navigation;
+pub mod spatial_index;
 
 struct SphericalHexExtension;
 
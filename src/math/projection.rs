@@ -1,10 +1,41 @@
 use glam::{Vec3, vec3};
+use std::collections::HashMap;
 
 // Golden ratio components for icosahedron construction
 const PHI: f32 = 1.618033988749895;
 const PHI_NORM: f32 = 0.8506508083520399; // 1/sqrt(1 + phi^2)
 const ONE_NORM: f32 = 0.5257311121191336; // 1/sqrt(1 + phi^2)
 
+// Index table shared between the per-face icosahedron (`generate_icosahedron`)
+// and the shared-vertex mesh used for the hexsphere dual (`generate_icosphere`).
+const ICOSAHEDRON_FACE_INDICES: [[usize; 3]; 20] = [
+    // Top pentagon
+    [0, 4, 8],  [0, 8, 10], [0, 10, 5], [0, 5, 1], [0, 1, 4],
+    // Middle strip
+    [4, 1, 9],  [8, 4, 6],  [10, 8, 2], [5, 10, 7], [1, 5, 11],
+    // Bottom pentagon
+    [3, 6, 9],  [3, 9, 11], [3, 11, 7], [3, 7, 2], [3, 2, 6],
+    // Connecting triangles
+    [9, 6, 4],  [6, 2, 8],  [2, 7, 10], [7, 11, 5], [11, 9, 1]
+];
+
+fn icosahedron_vertices() -> [Vec3; 12] {
+    [
+        vec3(ONE_NORM, 0.0, PHI_NORM),      // 0
+        vec3(-ONE_NORM, 0.0, PHI_NORM),     // 1
+        vec3(ONE_NORM, 0.0, -PHI_NORM),     // 2
+        vec3(-ONE_NORM, 0.0, -PHI_NORM),    // 3
+        vec3(0.0, PHI_NORM, ONE_NORM),      // 4
+        vec3(0.0, -PHI_NORM, ONE_NORM),     // 5
+        vec3(0.0, PHI_NORM, -ONE_NORM),     // 6
+        vec3(0.0, -PHI_NORM, -ONE_NORM),    // 7
+        vec3(PHI_NORM, ONE_NORM, 0.0),      // 8
+        vec3(-PHI_NORM, ONE_NORM, 0.0),     // 9
+        vec3(PHI_NORM, -ONE_NORM, 0.0),     // 10
+        vec3(-PHI_NORM, -ONE_NORM, 0.0)     // 11
+    ]
+}
+
 #[derive(Debug)]
 pub struct IcosahedronFace {
     pub vertices: [Vec3; 3],
@@ -60,38 +91,12 @@ impl Clone for IcosahedronFace {
 }
 
 pub fn generate_icosahedron() -> Vec<IcosahedronFace> {
-    // Generate the 12 vertices of the icosahedron
-    let vertices = [
-        vec3(ONE_NORM, 0.0, PHI_NORM),      // 0
-        vec3(-ONE_NORM, 0.0, PHI_NORM),     // 1
-        vec3(ONE_NORM, 0.0, -PHI_NORM),     // 2
-        vec3(-ONE_NORM, 0.0, -PHI_NORM),    // 3
-        vec3(0.0, PHI_NORM, ONE_NORM),      // 4
-        vec3(0.0, -PHI_NORM, ONE_NORM),     // 5
-        vec3(0.0, PHI_NORM, -ONE_NORM),     // 6
-        vec3(0.0, -PHI_NORM, -ONE_NORM),    // 7
-        vec3(PHI_NORM, ONE_NORM, 0.0),      // 8
-        vec3(-PHI_NORM, ONE_NORM, 0.0),     // 9
-        vec3(PHI_NORM, -ONE_NORM, 0.0),     // 10
-        vec3(-PHI_NORM, -ONE_NORM, 0.0)     // 11
-    ];
-
-    // Define the 20 faces of the icosahedron
-    let face_indices = [
-        // Top pentagon
-        [0, 4, 8],  [0, 8, 10], [0, 10, 5], [0, 5, 1], [0, 1, 4],
-        // Middle strip
-        [4, 1, 9],  [8, 4, 6],  [10, 8, 2], [5, 10, 7], [1, 5, 11],
-        // Bottom pentagon
-        [3, 6, 9],  [3, 9, 11], [3, 11, 7], [3, 7, 2], [3, 2, 6],
-        // Connecting triangles
-        [9, 6, 4],  [6, 2, 8],  [2, 7, 10], [7, 11, 5], [11, 9, 1]
-    ];
-
-    face_indices.iter()
+    let vertices = icosahedron_vertices();
+
+    ICOSAHEDRON_FACE_INDICES.iter()
         .map(|[i1, i2, i3]| IcosahedronFace::new(
-            vertices[*i1], 
-            vertices[*i2], 
+            vertices[*i1],
+            vertices[*i2],
             vertices[*i3]
         ))
         .collect()
@@ -107,6 +112,254 @@ pub fn calculate_face_area(face: &IcosahedronFace) -> f32 {
     a.cross(b).length() / 2.0
 }
 
+/// A triangle mesh where adjacent faces share vertex indices, unlike the
+/// independent per-face triangles produced by `IcosahedronFace::subdivide`.
+/// The first 12 vertices are always the original icosahedron corners.
+pub struct IcoMesh {
+    pub vertices: Vec<Vec3>,
+    pub faces: Vec<[usize; 3]>,
+}
+
+fn midpoint_index(a: usize, b: usize, vertices: &mut Vec<Vec3>, cache: &mut HashMap<(usize, usize), usize>) -> usize {
+    let key = if a < b { (a, b) } else { (b, a) };
+    if let Some(&existing) = cache.get(&key) {
+        return existing;
+    }
+
+    let mid = ((vertices[a] + vertices[b]) * 0.5).normalize();
+    let idx = vertices.len();
+    vertices.push(mid);
+    cache.insert(key, idx);
+    idx
+}
+
+/// Subdivides the icosahedron `resolution` times as a single shared-vertex
+/// mesh, deduping edge midpoints so neighboring triangles share vertices
+/// instead of each carrying their own copy.
+pub fn generate_icosphere(resolution: i32) -> IcoMesh {
+    let mut vertices = icosahedron_vertices().to_vec();
+    let mut faces: Vec<[usize; 3]> = ICOSAHEDRON_FACE_INDICES.to_vec();
+
+    for _ in 0..resolution {
+        let mut cache: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut next_faces = Vec::with_capacity(faces.len() * 4);
+
+        for [i0, i1, i2] in faces {
+            let m01 = midpoint_index(i0, i1, &mut vertices, &mut cache);
+            let m12 = midpoint_index(i1, i2, &mut vertices, &mut cache);
+            let m20 = midpoint_index(i2, i0, &mut vertices, &mut cache);
+
+            next_faces.push([i0, m01, m20]);
+            next_faces.push([m01, i1, m12]);
+            next_faces.push([m20, m12, i2]);
+            next_faces.push([m01, m12, m20]);
+        }
+
+        faces = next_faces;
+    }
+
+    IcoMesh { vertices, faces }
+}
+
+/// A mesh of arbitrary (not necessarily triangular) shared-vertex faces,
+/// used as the working representation for the Conway/Hart operator
+/// pipeline below.
+pub struct PolyMesh {
+    pub vertices: Vec<Vec3>,
+    pub faces: Vec<Vec<usize>>,
+}
+
+impl PolyMesh {
+    pub fn from_icosahedron() -> Self {
+        Self {
+            vertices: icosahedron_vertices().to_vec(),
+            faces: ICOSAHEDRON_FACE_INDICES.iter().map(|f| f.to_vec()).collect(),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Corner {
+    prev: usize,
+    next: usize,
+    face: usize,
+}
+
+/// For every vertex, the (prev, next, face) triples of every face corner
+/// touching it — the raw material for walking its incident edges/faces in
+/// order.
+fn vertex_corners(mesh: &PolyMesh) -> Vec<Vec<Corner>> {
+    let mut corners = vec![Vec::new(); mesh.vertices.len()];
+    for (face_index, face) in mesh.faces.iter().enumerate() {
+        let n = face.len();
+        for i in 0..n {
+            let prev = face[(i + n - 1) % n];
+            let v = face[i];
+            let next = face[(i + 1) % n];
+            corners[v].push(Corner { prev, next, face: face_index });
+        }
+    }
+    corners
+}
+
+/// Chains a vertex's corners into cyclic order by matching each corner's
+/// `next` to the following corner's `prev`, so its neighbor edges and
+/// incident faces come out in true angular order instead of incidence order.
+fn order_corners(corners: &[Corner]) -> Vec<Corner> {
+    if corners.is_empty() {
+        return Vec::new();
+    }
+
+    let mut remaining = corners.to_vec();
+    let mut ordered = vec![remaining.remove(0)];
+
+    while !remaining.is_empty() {
+        let current_next = ordered.last().unwrap().next;
+        match remaining.iter().position(|c| c.prev == current_next) {
+            Some(pos) => ordered.push(remaining.remove(pos)),
+            None => break,
+        }
+    }
+
+    ordered
+}
+
+/// Cuts each vertex, replacing it with a face whose corners sit a fraction
+/// along each incident edge.
+fn truncate(mesh: &PolyMesh) -> PolyMesh {
+    const CUT: f32 = 1.0 / 3.0;
+    let mut vertices = Vec::new();
+    let mut cache: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut faces = Vec::new();
+
+    fn edge_point(from: usize, to: usize, t: f32, original: &[Vec3], vertices: &mut Vec<Vec3>, cache: &mut HashMap<(usize, usize), usize>) -> usize {
+        if let Some(&idx) = cache.get(&(from, to)) {
+            return idx;
+        }
+        let point = (original[from] * (1.0 - t) + original[to] * t).normalize();
+        let idx = vertices.len();
+        vertices.push(point);
+        cache.insert((from, to), idx);
+        idx
+    }
+
+    for face in &mesh.faces {
+        let n = face.len();
+        let mut new_face = Vec::with_capacity(n * 2);
+        for i in 0..n {
+            let a = face[i];
+            let b = face[(i + 1) % n];
+            new_face.push(edge_point(a, b, CUT, &mesh.vertices, &mut vertices, &mut cache));
+            new_face.push(edge_point(b, a, CUT, &mesh.vertices, &mut vertices, &mut cache));
+        }
+        faces.push(new_face);
+    }
+
+    for (v, corners) in vertex_corners(mesh).iter().enumerate() {
+        let ring = order_corners(corners);
+        let face: Vec<usize> = ring.iter()
+            .map(|corner| edge_point(v, corner.next, CUT, &mesh.vertices, &mut vertices, &mut cache))
+            .collect();
+        if face.len() >= 3 {
+            faces.push(face);
+        }
+    }
+
+    PolyMesh { vertices, faces }
+}
+
+/// Vertices become faces and faces become vertices: each new vertex is a
+/// face centroid, and each new face rings the centroids of the faces around
+/// one original vertex.
+fn dual(mesh: &PolyMesh) -> PolyMesh {
+    let vertices: Vec<Vec3> = mesh.faces.iter()
+        .map(|face| {
+            let sum: Vec3 = face.iter().map(|&i| mesh.vertices[i]).sum();
+            (sum / face.len() as f32).normalize()
+        })
+        .collect();
+
+    let faces = vertex_corners(mesh).iter()
+        .map(|corners| order_corners(corners).iter().map(|c| c.face).collect::<Vec<_>>())
+        .filter(|face: &Vec<usize>| face.len() >= 3)
+        .collect();
+
+    PolyMesh { vertices, faces }
+}
+
+/// Rectifies the mesh: new vertices sit at edge midpoints, original faces
+/// shrink to rings of their own edge midpoints, and a new face appears at
+/// each original vertex ringing the midpoints of its incident edges.
+fn ambo(mesh: &PolyMesh) -> PolyMesh {
+    let mut vertices = Vec::new();
+    let mut cache: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut faces = Vec::new();
+
+    fn midpoint_vertex(a: usize, b: usize, original: &[Vec3], vertices: &mut Vec<Vec3>, cache: &mut HashMap<(usize, usize), usize>) -> usize {
+        let key = if a < b { (a, b) } else { (b, a) };
+        if let Some(&idx) = cache.get(&key) {
+            return idx;
+        }
+        let point = ((original[a] + original[b]) * 0.5).normalize();
+        let idx = vertices.len();
+        vertices.push(point);
+        cache.insert(key, idx);
+        idx
+    }
+
+    for face in &mesh.faces {
+        let n = face.len();
+        let new_face: Vec<usize> = (0..n)
+            .map(|i| midpoint_vertex(face[i], face[(i + 1) % n], &mesh.vertices, &mut vertices, &mut cache))
+            .collect();
+        faces.push(new_face);
+    }
+
+    for (v, corners) in vertex_corners(mesh).iter().enumerate() {
+        let ring = order_corners(corners);
+        let face: Vec<usize> = ring.iter()
+            .map(|corner| midpoint_vertex(v, corner.next, &mesh.vertices, &mut vertices, &mut cache))
+            .collect();
+        if face.len() >= 3 {
+            faces.push(face);
+        }
+    }
+
+    PolyMesh { vertices, faces }
+}
+
+/// Applies one Conway-notation operator letter; unrecognized letters pass
+/// the mesh through unchanged.
+pub fn apply_conway_operator(mesh: &PolyMesh, op: char) -> PolyMesh {
+    match op {
+        't' => truncate(mesh),
+        'd' => dual(mesh),
+        'a' => ambo(mesh),
+        _ => PolyMesh { vertices: mesh.vertices.clone(), faces: mesh.faces.clone() },
+    }
+}
+
+/// Builds a polyhedron mesh from a Conway-notation string such as `"tI"`
+/// (truncated icosahedron) or `"dI"` (its dual, the dodecahedron). The last
+/// character selects the seed polyhedron; operator characters before it are
+/// applied right-to-left, matching standard Conway notation. Returns `None`
+/// for an unrecognized seed.
+pub fn build_from_conway_notation(notation: &str) -> Option<PolyMesh> {
+    let mut chars = notation.trim().chars();
+    let seed = chars.next_back()?;
+
+    let mut mesh = match seed {
+        'I' | 'i' => PolyMesh::from_icosahedron(),
+        _ => return None,
+    };
+
+    for op in chars.rev() {
+        mesh = apply_conway_operator(&mesh, op);
+    }
+
+    Some(mesh)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -144,4 +397,49 @@ mod tests {
             assert!(normal.dot(face.normal) > 0.0);
         }
     }
+
+    #[test]
+    fn test_icosphere_shares_vertices() {
+        // Euler's formula for a subdivided icosahedron: V = 10*4^res + 2
+        let mesh = generate_icosphere(1);
+        assert_eq!(mesh.vertices.len(), 42);
+        assert_eq!(mesh.faces.len(), 80);
+
+        for vertex in &mesh.vertices {
+            assert!((vertex.length() - 1.0).abs() < 0.0001);
+        }
+    }
+
+    #[test]
+    fn test_truncated_icosahedron_is_a_soccer_ball() {
+        let mesh = build_from_conway_notation("tI").unwrap();
+        let pentagons = mesh.faces.iter().filter(|f| f.len() == 5).count();
+        let hexagons = mesh.faces.iter().filter(|f| f.len() == 6).count();
+
+        assert_eq!(pentagons, 12);
+        assert_eq!(hexagons, 20);
+    }
+
+    #[test]
+    fn test_dual_of_icosahedron_is_a_dodecahedron() {
+        let mesh = build_from_conway_notation("dI").unwrap();
+        assert_eq!(mesh.vertices.len(), 20);
+        assert!(mesh.faces.iter().all(|f| f.len() == 5));
+    }
+
+    #[test]
+    fn test_ambo_of_icosahedron_is_an_icosidodecahedron() {
+        let mesh = build_from_conway_notation("aI").unwrap();
+        let triangles = mesh.faces.iter().filter(|f| f.len() == 3).count();
+        let pentagons = mesh.faces.iter().filter(|f| f.len() == 5).count();
+
+        assert_eq!(mesh.vertices.len(), 30);
+        assert_eq!(triangles, 20);
+        assert_eq!(pentagons, 12);
+    }
+
+    #[test]
+    fn test_unknown_seed_returns_none() {
+        assert!(build_from_conway_notation("tX").is_none());
+    }
 }
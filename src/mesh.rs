@@ -0,0 +1,203 @@
+use godot::prelude::*;
+use godot::classes::{ArrayMesh, mesh::PrimitiveType, mesh::ArrayType};
+use glam::Vec3;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, Write};
+
+/// Builds a triangle-fan mesh from a tile's local-space polygon ring around
+/// its center, with normals pointing along the tile's local outward axis.
+pub fn build_tile_mesh(polygon: &[Vec3]) -> Gd<ArrayMesh> {
+    let mut verts = PackedVector3Array::new();
+    let mut normals = PackedVector3Array::new();
+    let mut indices = PackedInt32Array::new();
+
+    let outward = Vector3::new(0.0, 0.0, 1.0);
+    verts.push(Vector3::ZERO);
+    normals.push(outward);
+    for p in polygon {
+        verts.push(Vector3::new(p.x, p.y, p.z));
+        normals.push(outward);
+    }
+
+    let ring_len = polygon.len() as i32;
+    for i in 0..ring_len {
+        indices.push(0);
+        indices.push(i + 1);
+        indices.push((i + 1) % ring_len + 1);
+    }
+
+    let mut surface_array = VariantArray::new();
+    surface_array.resize(ArrayType::MAX.ord() as usize);
+    surface_array.set(ArrayType::VERTEX.ord() as usize, &verts.to_variant());
+    surface_array.set(ArrayType::NORMAL.ord() as usize, &normals.to_variant());
+    surface_array.set(ArrayType::INDEX.ord() as usize, &indices.to_variant());
+
+    let mut array_mesh = ArrayMesh::new_gd();
+    array_mesh.add_surface_from_arrays(PrimitiveType::TRIANGLES, &surface_array);
+    array_mesh
+}
+
+fn quantize(p: Vec3) -> (i64, i64, i64) {
+    const SCALE: f32 = 100_000.0;
+    ((p.x * SCALE).round() as i64, (p.y * SCALE).round() as i64, (p.z * SCALE).round() as i64)
+}
+
+fn weld_vertex(p: Vec3, lookup: &mut HashMap<(i64, i64, i64), usize>, vertices: &mut Vec<Vec3>) -> usize {
+    let key = quantize(p);
+    if let Some(&idx) = lookup.get(&key) {
+        return idx;
+    }
+
+    let idx = vertices.len();
+    vertices.push(p);
+    lookup.insert(key, idx);
+    idx
+}
+
+/// Welds every tile's center + polygon ring into one shared vertex/triangle
+/// mesh, fanning the ring from the center and flipping any triangle whose
+/// winding points inward.
+fn weld_tiles(tiles: &[(Vec3, Vec<Vec3>)]) -> (Vec<Vec3>, Vec<[usize; 3]>) {
+    let mut lookup = HashMap::new();
+    let mut vertices = Vec::new();
+    let mut triangles = Vec::new();
+
+    for (center, polygon) in tiles {
+        let center_idx = weld_vertex(*center, &mut lookup, &mut vertices);
+        let ring: Vec<usize> = polygon.iter().map(|p| weld_vertex(*p, &mut lookup, &mut vertices)).collect();
+        let ring_len = ring.len();
+
+        for i in 0..ring_len {
+            let (a, b, c) = (center_idx, ring[i], ring[(i + 1) % ring_len]);
+            let normal = (vertices[b] - vertices[a]).cross(vertices[c] - vertices[a]);
+            let radial = vertices[a].normalize();
+
+            if normal.dot(radial) < 0.0 {
+                triangles.push([a, c, b]);
+            } else {
+                triangles.push([a, b, c]);
+            }
+        }
+    }
+
+    (vertices, triangles)
+}
+
+pub fn export_obj(tiles: &[(Vec3, Vec<Vec3>)], path: &str) -> io::Result<()> {
+    let (vertices, triangles) = weld_tiles(tiles);
+    let mut file = File::create(path)?;
+
+    for v in &vertices {
+        writeln!(file, "v {} {} {}", v.x, v.y, v.z)?;
+    }
+    for [a, b, c] in &triangles {
+        writeln!(file, "f {} {} {}", a + 1, b + 1, c + 1)?;
+    }
+
+    Ok(())
+}
+
+pub fn export_stl(tiles: &[(Vec3, Vec<Vec3>)], path: &str) -> io::Result<()> {
+    let (vertices, triangles) = weld_tiles(tiles);
+    let mut file = File::create(path)?;
+
+    file.write_all(&[0u8; 80])?;
+    file.write_all(&(triangles.len() as u32).to_le_bytes())?;
+
+    for [a, b, c] in &triangles {
+        let (va, vb, vc) = (vertices[*a], vertices[*b], vertices[*c]);
+        let normal = (vb - va).cross(vc - va).normalize();
+
+        for component in [normal.x, normal.y, normal.z, va.x, va.y, va.z, vb.x, vb.y, vb.z, vc.x, vc.y, vc.z] {
+            file.write_all(&component.to_le_bytes())?;
+        }
+        file.write_all(&0u16.to_le_bytes())?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_tiles() -> Vec<(Vec3, Vec<Vec3>)> {
+        // Two adjacent tiles from a coarse hexsphere-like layout, close
+        // enough that their ring vertices weld together.
+        let center_a = Vec3::new(0.0, 0.0, 1.0);
+        let ring_a = vec![
+            Vec3::new(0.5, 0.0, 0.866),
+            Vec3::new(0.0, 0.5, 0.866),
+            Vec3::new(-0.5, 0.0, 0.866),
+            Vec3::new(0.0, -0.5, 0.866),
+        ];
+
+        let center_b = Vec3::new(0.0, 0.5, 0.866);
+        let ring_b = vec![
+            Vec3::new(0.0, 0.0, 1.0),
+            Vec3::new(0.5, 0.0, 0.866),
+            Vec3::new(0.5, 1.0, 0.866),
+            Vec3::new(0.0, 1.0, 1.0),
+        ];
+
+        vec![(center_a, ring_a), (center_b, ring_b)]
+    }
+
+    #[test]
+    fn test_weld_tiles_shares_duplicate_vertices() {
+        let tiles = sample_tiles();
+        let (vertices, triangles) = weld_tiles(&tiles);
+
+        // 5 + 5 ring-plus-center vertices minus the 3 shared between the tiles.
+        assert_eq!(vertices.len(), 7);
+        assert_eq!(triangles.len(), 4 + 4);
+    }
+
+    #[test]
+    fn test_weld_tiles_windings_point_outward() {
+        let tiles = sample_tiles();
+        let (vertices, triangles) = weld_tiles(&tiles);
+
+        for &[a, b, c] in &triangles {
+            let (va, vb, vc) = (vertices[a], vertices[b], vertices[c]);
+            let normal = (vb - va).cross(vc - va);
+            let radial = va.normalize();
+            assert!(normal.dot(radial) >= 0.0);
+        }
+    }
+
+    #[test]
+    fn test_export_obj_round_trips_vertex_and_face_count() -> io::Result<()> {
+        let tiles = sample_tiles();
+        let (vertices, triangles) = weld_tiles(&tiles);
+
+        let path = std::env::temp_dir().join("spherical_hex_test_export.obj");
+        export_obj(&tiles, path.to_str().unwrap())?;
+        let contents = std::fs::read_to_string(&path)?;
+        std::fs::remove_file(&path)?;
+
+        let v_count = contents.lines().filter(|l| l.starts_with("v ")).count();
+        let f_count = contents.lines().filter(|l| l.starts_with("f ")).count();
+        assert_eq!(v_count, vertices.len());
+        assert_eq!(f_count, triangles.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_export_stl_round_trips_triangle_count() -> io::Result<()> {
+        let tiles = sample_tiles();
+        let (_, triangles) = weld_tiles(&tiles);
+
+        let path = std::env::temp_dir().join("spherical_hex_test_export.stl");
+        export_stl(&tiles, path.to_str().unwrap())?;
+        let bytes = std::fs::read(&path)?;
+        std::fs::remove_file(&path)?;
+
+        let header_len = 80 + 4;
+        assert_eq!(bytes.len(), header_len + triangles.len() * 50);
+        let count = u32::from_le_bytes(bytes[80..84].try_into().unwrap());
+        assert_eq!(count as usize, triangles.len());
+        Ok(())
+    }
+}
@@ -0,0 +1,285 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+
+use glam::Vec3;
+
+use crate::hex::HexCoord;
+use crate::hex_grid::HexGrid;
+
+/// A graph/navigation layer over the generated tiles, built from their real
+/// neighbor links rather than cube-coordinate arithmetic (which breaks down
+/// across pentagon and subdivided-face boundaries).
+pub struct TileGraph {
+    positions: HashMap<HexCoord, Vec3>,
+    neighbors: HashMap<HexCoord, Vec<HexCoord>>,
+    /// The largest great-circle angle between any two linked tiles, used to
+    /// rescale the A* heuristic into hop units (see `heuristic_hops`).
+    max_hop_angle: f32,
+}
+
+impl TileGraph {
+    pub fn from_grid(grid: &HexGrid) -> Self {
+        let positions: HashMap<HexCoord, Vec3> =
+            grid.tiles().iter().map(|t| (t.coord, t.position)).collect();
+        let neighbors: HashMap<HexCoord, Vec<HexCoord>> =
+            grid.tiles().iter().map(|t| (t.coord, t.neighbor_coords.clone())).collect();
+
+        let max_hop_angle = neighbors.iter()
+            .flat_map(|(&coord, list)| list.iter().map(move |&n| (coord, n)))
+            .filter_map(|(a, b)| Some((*positions.get(&a)?, *positions.get(&b)?)))
+            .map(|(pa, pb)| pa.normalize().dot(pb.normalize()).clamp(-1.0, 1.0).acos())
+            .fold(f32::EPSILON, f32::max);
+
+        Self { positions, neighbors, max_hop_angle }
+    }
+
+    fn neighbors_of(&self, coord: HexCoord) -> impl Iterator<Item = HexCoord> + '_ {
+        self.neighbors.get(&coord).into_iter().flatten().copied()
+    }
+
+    /// Hop-count distance via BFS over the real tile adjacency graph.
+    pub fn distance(&self, a: HexCoord, b: HexCoord) -> Option<usize> {
+        if a == b {
+            return Some(0);
+        }
+
+        let mut visited = HashSet::new();
+        visited.insert(a);
+        let mut frontier = VecDeque::new();
+        frontier.push_back((a, 0));
+
+        while let Some((coord, dist)) = frontier.pop_front() {
+            for neighbor in self.neighbors_of(coord) {
+                if neighbor == b {
+                    return Some(dist + 1);
+                }
+                if visited.insert(neighbor) {
+                    frontier.push_back((neighbor, dist + 1));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// All tiles reachable within `n` hops of `origin`, via breadth-first expansion.
+    pub fn tiles_in_range(&self, origin: HexCoord, n: usize) -> Vec<HexCoord> {
+        let mut visited = HashMap::new();
+        visited.insert(origin, 0usize);
+        let mut frontier = VecDeque::new();
+        frontier.push_back(origin);
+
+        while let Some(coord) = frontier.pop_front() {
+            let dist = visited[&coord];
+            if dist == n {
+                continue;
+            }
+
+            for neighbor in self.neighbors_of(coord) {
+                if !visited.contains_key(&neighbor) {
+                    visited.insert(neighbor, dist + 1);
+                    frontier.push_back(neighbor);
+                }
+            }
+        }
+
+        visited.into_keys().collect()
+    }
+
+    /// Supercover-style line walk: at each step, moves to whichever neighbor's
+    /// direction best aligns with the great-circle bearing toward `goal`.
+    pub fn line(&self, start: HexCoord, goal: HexCoord) -> Vec<HexCoord> {
+        let mut path = vec![start];
+        if !self.positions.contains_key(&start) {
+            return path;
+        }
+        let Some(&goal_pos) = self.positions.get(&goal) else {
+            return path;
+        };
+
+        let mut current = start;
+        while current != goal {
+            let current_pos = self.positions[&current];
+            let target_dir = (goal_pos - current_pos).normalize();
+
+            let next = self.neighbors_of(current).max_by(|a, b| {
+                let dir_a = (self.positions[a] - current_pos).normalize();
+                let dir_b = (self.positions[b] - current_pos).normalize();
+                dir_a.dot(target_dir).partial_cmp(&dir_b.dot(target_dir)).unwrap_or(Ordering::Equal)
+            });
+
+            match next {
+                Some(n) if n != current && !path.contains(&n) => {
+                    path.push(n);
+                    current = n;
+                }
+                _ => break,
+            }
+        }
+
+        path
+    }
+
+    /// A* search using hop count as edge cost and the great-circle angle
+    /// between tile centers, rescaled into hop units, as the heuristic.
+    pub fn find_path(&self, start: HexCoord, goal: HexCoord) -> Option<Vec<HexCoord>> {
+        if !self.positions.contains_key(&start) || !self.positions.contains_key(&goal) {
+            return None;
+        }
+
+        let mut open = BinaryHeap::new();
+        let mut came_from: HashMap<HexCoord, HexCoord> = HashMap::new();
+        let mut g_score: HashMap<HexCoord, f32> = HashMap::new();
+        let mut closed = HashSet::new();
+
+        g_score.insert(start, 0.0);
+        open.push(ScoredCoord { cost: self.heuristic_hops(start, goal), coord: start });
+
+        while let Some(ScoredCoord { coord, .. }) = open.pop() {
+            if coord == goal {
+                return Some(reconstruct_path(&came_from, goal));
+            }
+            if !closed.insert(coord) {
+                continue;
+            }
+
+            for neighbor in self.neighbors_of(coord) {
+                let tentative = g_score[&coord] + 1.0;
+                if tentative < *g_score.get(&neighbor).unwrap_or(&f32::INFINITY) {
+                    came_from.insert(neighbor, coord);
+                    g_score.insert(neighbor, tentative);
+                    let f = tentative + self.heuristic_hops(neighbor, goal);
+                    open.push(ScoredCoord { cost: f, coord: neighbor });
+                }
+            }
+        }
+
+        None
+    }
+
+    fn great_circle_angle(&self, a: HexCoord, b: HexCoord) -> f32 {
+        let pa = self.positions[&a].normalize();
+        let pb = self.positions[&b].normalize();
+        pa.dot(pb).clamp(-1.0, 1.0).acos()
+    }
+
+    /// The great-circle angle to `goal` expressed in hop units: dividing by
+    /// `max_hop_angle` (the widest single-hop angle anywhere in the graph)
+    /// guarantees this never overestimates the true remaining hop count, so
+    /// it stays admissible at coarse resolutions and sparse Conway
+    /// topologies where per-hop angles can exceed 1 radian.
+    fn heuristic_hops(&self, a: HexCoord, b: HexCoord) -> f32 {
+        self.great_circle_angle(a, b) / self.max_hop_angle
+    }
+}
+
+fn reconstruct_path(came_from: &HashMap<HexCoord, HexCoord>, goal: HexCoord) -> Vec<HexCoord> {
+    let mut path = vec![goal];
+    let mut current = goal;
+    while let Some(&prev) = came_from.get(&current) {
+        path.push(prev);
+        current = prev;
+    }
+    path.reverse();
+    path
+}
+
+struct ScoredCoord {
+    cost: f32,
+    coord: HexCoord,
+}
+
+impl PartialEq for ScoredCoord {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+impl Eq for ScoredCoord {}
+
+impl PartialOrd for ScoredCoord {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ScoredCoord {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed so `BinaryHeap` (a max-heap) pops the lowest cost first.
+        other.cost.partial_cmp(&self.cost).unwrap_or(Ordering::Equal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex_grid::HexGridSettings;
+
+    #[test]
+    fn test_distance_between_neighbors_is_one() {
+        let grid = HexGrid::new(HexGridSettings { resolution: 1, topology: None });
+        let graph = TileGraph::from_grid(&grid);
+        let tile = &grid.tiles()[0];
+        let neighbor = tile.neighbor_coords[0];
+
+        assert_eq!(graph.distance(tile.coord, neighbor), Some(1));
+    }
+
+    #[test]
+    fn test_tiles_in_range_includes_origin() {
+        let grid = HexGrid::new(HexGridSettings { resolution: 1, topology: None });
+        let graph = TileGraph::from_grid(&grid);
+        let origin = grid.tiles()[0].coord;
+
+        let in_range = graph.tiles_in_range(origin, 1);
+        assert!(in_range.contains(&origin));
+        assert!(in_range.len() > 1);
+    }
+
+    #[test]
+    fn test_find_path_reaches_goal() {
+        let grid = HexGrid::new(HexGridSettings { resolution: 1, topology: None });
+        let graph = TileGraph::from_grid(&grid);
+        let start = grid.tiles()[0].coord;
+        let goal = grid.tiles()[grid.tiles().len() / 2].coord;
+
+        let path = graph.find_path(start, goal).expect("path should exist");
+        assert_eq!(*path.first().unwrap(), start);
+        assert_eq!(*path.last().unwrap(), goal);
+    }
+
+    #[test]
+    fn test_find_path_with_unknown_start_returns_none() {
+        let grid = HexGrid::new(HexGridSettings { resolution: 1, topology: None });
+        let graph = TileGraph::from_grid(&grid);
+        let goal = grid.tiles()[0].coord;
+        let unknown = HexCoord::new(-1, -1);
+
+        assert!(graph.find_path(unknown, goal).is_none());
+    }
+
+    #[test]
+    fn test_line_with_unknown_start_returns_singleton_path() {
+        let grid = HexGrid::new(HexGridSettings { resolution: 1, topology: None });
+        let graph = TileGraph::from_grid(&grid);
+        let goal = grid.tiles()[0].coord;
+        let unknown = HexCoord::new(-1, -1);
+
+        assert_eq!(graph.line(unknown, goal), vec![unknown]);
+    }
+
+    #[test]
+    fn test_find_path_at_resolution_zero_is_shortest() {
+        // At resolution 0 adjacent tile centers are ~1.107 rad apart, well
+        // over the 1.0 flat hop cost, so an unscaled angle heuristic would
+        // stop being admissible here; confirm A* still finds a shortest hop
+        // path by cross-checking against BFS distance.
+        let grid = HexGrid::new(HexGridSettings { resolution: 0, topology: None });
+        let graph = TileGraph::from_grid(&grid);
+        let start = grid.tiles()[0].coord;
+        let goal = grid.tiles()[grid.tiles().len() / 2].coord;
+
+        let path = graph.find_path(start, goal).expect("path should exist");
+        let expected = graph.distance(start, goal).expect("bfs distance should exist");
+        assert_eq!(path.len() - 1, expected);
+    }
+}
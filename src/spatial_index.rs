@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+
+use glam::Vec3;
+
+use crate::hex::HexCoord;
+use crate::math::coordinates::cartesian_to_spherical;
+
+/// Default bucket width in radians of latitude/longitude; small enough to
+/// keep buckets sparsely populated at the resolutions this crate targets.
+pub const DEFAULT_CELL_SIZE: f32 = 0.15;
+
+/// Coarse lat/long bucketing of tile positions so nearest-tile queries only
+/// need to scan a handful of candidates instead of every tile on the sphere.
+pub struct SpatialIndex {
+    cell_size: f32,
+    buckets: HashMap<(i32, i32), Vec<HexCoord>>,
+    positions: HashMap<HexCoord, Vec3>,
+}
+
+impl SpatialIndex {
+    pub fn build(tiles: &[(HexCoord, Vec3)], cell_size: f32) -> Self {
+        let mut buckets: HashMap<(i32, i32), Vec<HexCoord>> = HashMap::new();
+        let mut positions = HashMap::new();
+
+        for &(coord, position) in tiles {
+            positions.insert(coord, position);
+            buckets.entry(cell_of(position, cell_size)).or_default().push(coord);
+        }
+
+        Self { cell_size, buckets, positions }
+    }
+
+    /// Returns the tile whose center is nearest `point`, searching the
+    /// point's bucket and its immediate neighbors first (wrapping the
+    /// longitude axis so the search doesn't drop candidates just across the
+    /// seam at `theta = ±π`) and falling back to a full scan both when that
+    /// search finds nothing and whenever `point` is near a pole, where
+    /// `atan2(z, x)` becomes numerically unstable and physically adjacent
+    /// tiles can land in arbitrarily different longitude buckets.
+    pub fn nearest(&self, point: Vec3) -> Option<HexCoord> {
+        let normalized = point.normalize();
+        let phi = cartesian_to_spherical(normalized).y;
+        if phi < self.cell_size || phi > std::f32::consts::PI - self.cell_size {
+            return self.nearest_brute_force(normalized);
+        }
+
+        let (cx, cy) = cell_of(normalized, self.cell_size);
+        let theta_cells = theta_bucket_count(self.cell_size);
+
+        let mut best: Option<(HexCoord, f32)> = None;
+        for dx in -1..=1 {
+            let wrapped_cx = (cx + dx).rem_euclid(theta_cells);
+            for dy in -1..=1 {
+                let Some(candidates) = self.buckets.get(&(wrapped_cx, cy + dy)) else { continue };
+                for &coord in candidates {
+                    let dist = self.positions[&coord].distance_squared(normalized);
+                    let is_better = match best {
+                        Some((_, best_dist)) => dist < best_dist,
+                        None => true,
+                    };
+                    if is_better {
+                        best = Some((coord, dist));
+                    }
+                }
+            }
+        }
+
+        best.map(|(coord, _)| coord).or_else(|| self.nearest_brute_force(normalized))
+    }
+
+    fn nearest_brute_force(&self, normalized: Vec3) -> Option<HexCoord> {
+        self.positions.iter()
+            .min_by(|(_, a), (_, b)| {
+                a.distance_squared(normalized).partial_cmp(&b.distance_squared(normalized)).unwrap()
+            })
+            .map(|(&coord, _)| coord)
+    }
+}
+
+/// Number of longitude buckets needed to cover a full `2π` revolution at
+/// `cell_size`, i.e. the modulus `theta`'s bucket index wraps around at.
+fn theta_bucket_count(cell_size: f32) -> i32 {
+    ((2.0 * std::f32::consts::PI / cell_size).ceil() as i32).max(1)
+}
+
+fn cell_of(point: Vec3, cell_size: f32) -> (i32, i32) {
+    let spherical = cartesian_to_spherical(point.normalize());
+    let cx = (spherical.x / cell_size).floor() as i32;
+    (cx.rem_euclid(theta_bucket_count(cell_size)), (spherical.y / cell_size).floor() as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nearest_finds_closest_of_two_tiles() {
+        let tiles = [
+            (HexCoord::new(0, 0), Vec3::new(1.0, 0.0, 0.0)),
+            (HexCoord::new(1, 0), Vec3::new(0.0, 1.0, 0.0)),
+        ];
+        let index = SpatialIndex::build(&tiles, DEFAULT_CELL_SIZE);
+
+        assert_eq!(index.nearest(Vec3::new(0.9, 0.1, 0.0)), Some(HexCoord::new(0, 0)));
+    }
+
+    #[test]
+    fn test_nearest_wraps_across_the_longitude_seam() {
+        // `a` sits just on the theta ≈ +π side of the seam and `b` just on
+        // the theta ≈ -π side: far apart in *unwrapped* cx (20 vs -21) even
+        // though they're physically close on the sphere, and adjacent buckets
+        // (20 and 21) only once cx wraps around. `b` is the true
+        // nearest tile to the query, which sits right on the seam.
+        let a = Vec3::new(-1.0, 0.0, 0.05).normalize();
+        let b = Vec3::new(-1.0, 0.0, -0.02).normalize();
+        let tiles = [(HexCoord::new(0, 0), a), (HexCoord::new(1, 0), b)];
+        let index = SpatialIndex::build(&tiles, DEFAULT_CELL_SIZE);
+
+        let query = Vec3::new(-1.0, 0.0, 0.0);
+        assert_eq!(index.nearest(query), Some(HexCoord::new(1, 0)));
+    }
+
+    #[test]
+    fn test_nearest_near_pole_falls_back_to_brute_force() {
+        use crate::math::coordinates::spherical_to_cartesian;
+        use std::f32::consts::PI;
+
+        // Five tiles within 0.01 rad of the north pole, spread across
+        // theta the way a real icosphere-dual pentagon's neighbors are:
+        // physically adjacent despite landing in far-apart theta buckets,
+        // since atan2(z, x) is unstable this close to the pole.
+        let phi = 0.01;
+        let tiles: Vec<(HexCoord, Vec3)> = (0..5)
+            .map(|i| {
+                let theta = 2.0 * PI * i as f32 / 5.0;
+                (HexCoord::new(i, 0), spherical_to_cartesian(theta, phi, 1.0))
+            })
+            .collect();
+        let index = SpatialIndex::build(&tiles, DEFAULT_CELL_SIZE);
+
+        // Query essentially on top of tile 2's own position.
+        let query = spherical_to_cartesian(2.0 * PI * 2.0 / 5.0, phi, 1.0);
+        assert_eq!(index.nearest(query), Some(HexCoord::new(2, 0)));
+    }
+}
@@ -1,11 +1,14 @@
 use godot::prelude::*;
-use glam::{Vec3, vec3};
+use glam::{Vec3, Mat3, vec3};
 use std::collections::HashMap;
 
 use crate::hex::HexTile;
 use crate::hex::HexCoord;
 use crate::hex_grid::{HexGrid, HexGridSettings};
 use crate::math::projection::{generate_icosahedron, IcosahedronFace};
+use crate::mesh;
+use crate::navigation::TileGraph;
+use crate::spatial_index::{SpatialIndex, DEFAULT_CELL_SIZE};
 
 #[derive(GodotClass)]
 #[class(base=Node3D)]
@@ -14,9 +17,16 @@ pub struct SphericalHexGrid {
     base: Base<Node3D>,
     radius: f32,
     resolution: i32,
-    hex_size: f32,
     faces: Vec<IcosahedronFace>,
     tiles: HashMap<String, Gd<HexTile>>,
+    // World-space (center, polygon ring) per tile, kept around for OBJ/STL export.
+    world_tiles: Vec<(Vec3, Vec<Vec3>)>,
+    nav: Option<TileGraph>,
+    spatial_index: Option<SpatialIndex>,
+    // Conway-notation string (e.g. "tI") selecting a fixed polyhedron
+    // topology instead of the subdivided-icosphere dual; `None` keeps the
+    // default resolution-based grid.
+    topology: Option<String>,
 }
 
 #[godot_api]
@@ -30,50 +40,64 @@ impl SphericalHexGrid {
         }
         self.tiles.clear();
 
-        // Generate base icosahedron and subdivide
+        // Keep the subdivided per-face triangles around for the closest-face
+        // search in `get_tile_at_position`.
         let base_faces = generate_icosahedron();
         self.faces = base_faces.iter()
             .flat_map(|face| face.subdivide(self.resolution))
             .collect();
 
-        // Create hex grid
-        let settings = HexGridSettings {
-            hex_size: self.hex_size,
-            grid_radius: (6.0 * 2.0_f32.powi(self.resolution)) as i32,
-        };
-        let mut hex_grid = HexGrid::new(settings);
+        // The dual of the shared-vertex icosphere is the actual hexsphere
+        // tiling: one seamless pentagon/hexagon mesh with no seams or
+        // overlapping tiles. A recognized Conway `topology` replaces it with
+        // the faces of that polyhedron.
+        let hex_grid = HexGrid::new(HexGridSettings {
+            resolution: self.resolution,
+            topology: self.topology.clone(),
+        });
 
-        // Generate hex grid for each face
-        for face in &self.faces {
-            hex_grid.generate_on_face(face.clone());
-        }
+        self.world_tiles = hex_grid.tiles().iter()
+            .map(|t| (t.position * self.radius, t.polygon.iter().map(|p| *p * self.radius).collect()))
+            .collect();
 
-        // Create hex tiles for each position
-        for (coord, position) in hex_grid.get_all_positions().iter() {
-            self.create_hex_tile_at(*position, face_normal_at_point(*position), *coord);
+        for grid_tile in hex_grid.tiles() {
+            self.create_hex_tile_at(grid_tile.position, grid_tile.coord, &grid_tile.polygon, grid_tile.is_pentagon);
         }
 
-        // First collect all neighbor positions
-        let mut neighbor_data = Vec::new();
-        for (key, _) in &self.tiles {
-            let coord = string_to_coord(key);
-            let neighbor_positions = hex_grid.get_neighbor_positions(&coord);
-            neighbor_data.push((key.clone(), neighbor_positions));
-        }
+        // Wire neighbors straight from the adjacency computed during
+        // generation: a key lookup per neighbor, no nearest-point search.
+        let neighbor_coords: Vec<_> = hex_grid.tiles().iter()
+            .map(|t| (coord_to_key(&t.coord), t.neighbor_coords.clone()))
+            .collect();
 
-        // Then update neighbors
-        for (key, positions) in neighbor_data {
-            let neighbors: Vec<_> = positions.iter()
-                .filter_map(|pos| self.get_tile_at_world_pos(*pos))
+        for (key, neighbors) in neighbor_coords {
+            let neighbor_tiles: Vec<_> = neighbors.iter()
+                .filter_map(|c| self.tiles.get(&coord_to_key(c)))
+                .cloned()
                 .collect();
 
             if let Some(tile) = self.tiles.get_mut(&key) {
-                tile.bind_mut().connect_neighbors(neighbors);
+                tile.bind_mut().connect_neighbors(neighbor_tiles);
             }
         }
+
+        self.spatial_index = Some(SpatialIndex::build(
+            &hex_grid.tiles().iter().map(|t| (t.coord, t.position * self.radius)).collect::<Vec<_>>(),
+            DEFAULT_CELL_SIZE,
+        ));
+        self.nav = Some(TileGraph::from_grid(&hex_grid));
     }
 
     fn get_tile_at_world_pos(&self, pos: Vec3) -> Option<Gd<HexTile>> {
+        if let Some(index) = &self.spatial_index {
+            if let Some(coord) = index.nearest(pos) {
+                if let Some(tile) = self.tiles.get(&coord_to_key(&coord)) {
+                    return Some(tile.clone());
+                }
+            }
+        }
+
+        // Fallback before the spatial index has been built for this grid.
         let godot_pos = Vector3::new(pos.x, pos.y, pos.z);
         self.tiles.values()
             .min_by(|a, b| {
@@ -86,41 +110,49 @@ impl SphericalHexGrid {
             .map(|tile| tile.clone())
     }
 
-    fn create_hex_tile_at(&mut self, position: Vec3, normal: Vec3, coord: HexCoord) -> Option<Gd<HexTile>> {
+    fn create_hex_tile_at(&mut self, position: Vec3, coord: HexCoord, polygon: &[Vec3], is_pentagon: bool) -> Option<Gd<HexTile>> {
         let mut tile = HexTile::new_alloc();
-        
-        // Set tile transform
-        let scale = self.hex_size * self.radius;
+
+        let normal = face_normal_at_point(position);
         let up = vec3(0.0, 1.0, 0.0);
-        
-        let basis = if normal.dot(up) > 0.999 {
-            Basis::IDENTITY
+
+        let local_basis = if normal.dot(up) > 0.999 {
+            Mat3::IDENTITY
         } else if normal.dot(up) < -0.999 {
-            // Create an inverted basis for bottom-facing tiles
-            Basis::from_euler(EulerOrder::XYZ, Vector3::new(std::f32::consts::PI, 0.0, 0.0))
+            Mat3::from_axis_angle(vec3(1.0, 0.0, 0.0), std::f32::consts::PI)
         } else {
             let right = up.cross(normal).normalize();
-            let up = normal.cross(right);
-            Basis::from_cols(
-                Vector3::new(right.x, right.y, right.z),
-                Vector3::new(up.x, up.y, up.z),
-                Vector3::new(normal.x, normal.y, normal.z)
-            )
+            let tile_up = normal.cross(right);
+            Mat3::from_cols(right, tile_up, normal)
         };
 
+        let basis = Basis::from_cols(
+            Vector3::new(local_basis.x_axis.x, local_basis.x_axis.y, local_basis.x_axis.z),
+            Vector3::new(local_basis.y_axis.x, local_basis.y_axis.y, local_basis.y_axis.z),
+            Vector3::new(local_basis.z_axis.x, local_basis.z_axis.y, local_basis.z_axis.z),
+        );
+
         let transform = Transform3D::new(
             basis,
             Vector3::new(position.x, position.y, position.z) * self.radius
         );
-        
+
         tile.set_transform(transform);
         tile.bind_mut().set_coordinate(coord.q, coord.r);
 
+        // Bring the polygon ring into the tile's local, unrotated space so
+        // downstream mesh generation can build it directly under this node.
+        let local_to_world_inv = local_basis.transpose();
+        let local_polygon: Vec<Vec3> = polygon.iter()
+            .map(|p| local_to_world_inv * ((*p - position) * self.radius))
+            .collect();
+        tile.bind_mut().set_polygon(local_polygon, is_pentagon);
+
         unsafe {
             let parent_node = self.base.to_gd();
             tile.reparent(&parent_node.upcast::<Node>());
         }
-        
+
         let key = coord_to_key(&coord);
         self.tiles.insert(key, tile.clone());
         Some(tile)
@@ -136,16 +168,20 @@ impl SphericalHexGrid {
         self.resolution = resolution.max(0);
     }
 
+    /// Sets a Conway-notation topology (e.g. `"tI"` for a soccer-ball
+    /// truncated icosahedron), overriding the resolution-based dual grid.
+    /// Pass an empty string to return to the default topology.
     #[func]
-    pub fn set_hex_size(&mut self, size: f32) {
-        self.hex_size = size;
+    pub fn set_topology(&mut self, topology: GString) {
+        let topology = topology.to_string();
+        self.topology = if topology.is_empty() { None } else { Some(topology) };
     }
 
     #[func]
     pub fn get_tile_at_position(&self, world_pos: Vector3) -> Option<Gd<HexTile>> {
         let pos = Vec3::new(world_pos.x, world_pos.y, world_pos.z);
         let normalized = pos.normalize();
-        
+
         // Find the closest face center
         self.faces.iter()
             .min_by(|a, b| {
@@ -155,6 +191,89 @@ impl SphericalHexGrid {
             })
             .and_then(|_| self.get_tile_at_world_pos(pos))
     }
+
+    #[func]
+    pub fn get_tile_under_ray(&self, origin: Vector3, direction: Vector3) -> Option<Gd<HexTile>> {
+        let origin = Vec3::new(origin.x, origin.y, origin.z);
+        let direction = Vec3::new(direction.x, direction.y, direction.z).normalize();
+        let center = self.base.get_global_position();
+        let center = Vec3::new(center.x, center.y, center.z);
+
+        let hit = ray_sphere_intersection(origin, direction, center, self.radius)?;
+        let world_hit = origin + direction * hit;
+        self.get_tile_at_position(Vector3::new(world_hit.x, world_hit.y, world_hit.z))
+    }
+
+    #[func]
+    pub fn export_obj(&self, path: GString) -> bool {
+        mesh::export_obj(&self.world_tiles, &path.to_string()).is_ok()
+    }
+
+    #[func]
+    pub fn export_stl(&self, path: GString) -> bool {
+        mesh::export_stl(&self.world_tiles, &path.to_string()).is_ok()
+    }
+
+    #[func]
+    pub fn get_tile_distance(&self, a: Gd<HexTile>, b: Gd<HexTile>) -> i32 {
+        let Some(nav) = &self.nav else { return -1 };
+        nav.distance(tile_coord(&a), tile_coord(&b))
+            .map(|d| d as i32)
+            .unwrap_or(-1)
+    }
+
+    #[func]
+    pub fn get_tiles_in_range(&self, origin: Gd<HexTile>, n: i32) -> Array<Gd<HexTile>> {
+        let Some(nav) = &self.nav else { return Array::new() };
+        nav.tiles_in_range(tile_coord(&origin), n.max(0) as usize).iter()
+            .filter_map(|coord| self.tiles.get(&coord_to_key(coord)))
+            .cloned()
+            .collect()
+    }
+
+    #[func]
+    pub fn find_path(&self, start: Gd<HexTile>, goal: Gd<HexTile>) -> Array<Gd<HexTile>> {
+        let Some(nav) = &self.nav else { return Array::new() };
+        let Some(path) = nav.find_path(tile_coord(&start), tile_coord(&goal)) else {
+            return Array::new();
+        };
+
+        path.iter()
+            .filter_map(|coord| self.tiles.get(&coord_to_key(coord)))
+            .cloned()
+            .collect()
+    }
+}
+
+fn tile_coord(tile: &Gd<HexTile>) -> HexCoord {
+    let c = tile.bind().get_coordinate();
+    HexCoord::new(c.x as i32, c.y as i32)
+}
+
+/// Analytic ray-sphere intersection; returns the nearer hit distance `t`
+/// along `direction`, or `None` if the ray misses the sphere entirely.
+fn ray_sphere_intersection(origin: Vec3, direction: Vec3, center: Vec3, radius: f32) -> Option<f32> {
+    let oc = origin - center;
+    let a = direction.dot(direction);
+    let b = oc.dot(direction);
+    let c = oc.dot(oc) - radius * radius;
+
+    let discriminant = b * b - a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+
+    let sqrt_disc = discriminant.sqrt();
+    let near = (-b - sqrt_disc) / a;
+    let far = (-b + sqrt_disc) / a;
+
+    if near >= 0.0 {
+        Some(near)
+    } else if far >= 0.0 {
+        Some(far)
+    } else {
+        None
+    }
 }
 
 #[godot_api]
@@ -164,9 +283,12 @@ impl INode3D for SphericalHexGrid {
             base,
             radius: 1.0,
             resolution: 1,
-            hex_size: 0.1,
             faces: Vec::new(),
             tiles: HashMap::new(),
+            world_tiles: Vec::new(),
+            nav: None,
+            spatial_index: None,
+            topology: None,
         }
     }
 
@@ -184,12 +306,42 @@ fn coord_to_key(coord: &HexCoord) -> String {
     format!("{}_{}", coord.q, coord.r)
 }
 
-fn string_to_coord(key: &str) -> HexCoord {
-    let parts: Vec<&str> = key.split('_').collect();
-    if parts.len() == 2 {
-        if let (Ok(q), Ok(r)) = (parts[0].parse::<i32>(), parts[1].parse::<i32>()) {
-            return HexCoord::new(q, r);
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ray_through_center_hits_near_side() {
+        let origin = vec3(0.0, 0.0, -5.0);
+        let direction = vec3(0.0, 0.0, 1.0);
+        let hit = ray_sphere_intersection(origin, direction, Vec3::ZERO, 1.0).unwrap();
+
+        assert!((hit - 4.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_ray_missing_sphere_returns_none() {
+        let origin = vec3(0.0, 5.0, -5.0);
+        let direction = vec3(0.0, 0.0, 1.0);
+
+        assert!(ray_sphere_intersection(origin, direction, Vec3::ZERO, 1.0).is_none());
+    }
+
+    #[test]
+    fn test_ray_tangent_to_sphere_hits_once() {
+        let origin = vec3(0.0, 1.0, -5.0);
+        let direction = vec3(0.0, 0.0, 1.0);
+        let hit = ray_sphere_intersection(origin, direction, Vec3::ZERO, 1.0).unwrap();
+
+        assert!((hit - 5.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_ray_from_inside_sphere_uses_far_root() {
+        let origin = Vec3::ZERO;
+        let direction = vec3(0.0, 0.0, 1.0);
+        let hit = ray_sphere_intersection(origin, direction, Vec3::ZERO, 1.0).unwrap();
+
+        assert!((hit - 1.0).abs() < 1e-5);
     }
-    HexCoord::new(0, 0)
 }